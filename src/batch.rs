@@ -0,0 +1,74 @@
+use crate::{Store, Vec};
+
+/// A batch of actions waiting to be dispatched to a store at once.
+///
+/// A batch is created by [`Store::begin_batch`](struct.Store.html#method.begin_batch).
+/// All actions dispatched through it are buffered until [`commit`](#method.commit) is called,
+/// which dispatches them in order and calls the subscriptions only once with the final state.
+/// Dropping a batch without committing it discards all buffered actions.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::Store;
+/// #
+/// # type State = i8;
+/// #
+/// # enum Action {
+/// #     Increment,
+/// #     Decrement
+/// # }
+/// #
+/// # fn reducer(state: &State, action: &Action) -> State {
+/// #     match action {
+/// #         Action::Increment => state + 1,
+/// #         Action::Decrement => state - 1
+/// #     }
+/// # }
+/// #
+/// let mut store = Store::new(reducer, 0);
+///
+/// {
+///     let mut batch = store.begin_batch();
+///     batch.dispatch(Action::Increment);
+///     // Dropped without committing, nothing happens.
+/// }
+///
+/// assert_eq!(*store.state(), 0);
+/// ```
+pub struct Batch<'a, State, Action> {
+    store: &'a mut Store<State, Action>,
+    actions: Vec<Action>
+}
+
+impl<'a, State, Action> Batch<'a, State, Action> {
+    /// Creates a new, empty batch for the store.
+    pub(crate) fn new(store: &'a mut Store<State, Action>) -> Self {
+        Self {
+            store,
+            actions: Vec::new()
+        }
+    }
+
+    /// Returns the state of the store, without any of the buffered actions applied.
+    pub fn state(&self) -> &State {
+        self.store.state()
+    }
+
+    /// Buffers an action to be dispatched when the batch gets committed.
+    pub fn dispatch(&mut self, action: Action) {
+        self.actions.push(action);
+    }
+
+    /// Dispatches all buffered actions in order, then calls the subscriptions once.
+    ///
+    /// A batch is not atomic: if the reducer or a middleware panics halfway through,
+    /// the actions dispatched before the panic stay applied and the subscriptions are not called.
+    /// Use a [`transaction`](struct.Store.html#method.transaction) to roll those back.
+    ///
+    /// While the store is [paused](struct.Store.html#method.pause), the batch is buffered as a whole
+    /// and applied when the store is resumed, after the actions dispatched before it.
+    pub fn commit(self) {
+        self.store.dispatch_batch(self.actions);
+    }
+}
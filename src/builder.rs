@@ -0,0 +1,138 @@
+#[cfg(feature = "std")]
+use crate::SubscriptionId;
+use crate::{Middleware, Preprocessor, Reducer, ReducerMut, Store, Subscription};
+#[cfg(feature = "std")]
+use std::any::Any;
+
+/// A builder to configure a store before creating it.
+///
+/// The builder doesn't hand out the ids of the middleware and subscriptions it adds,
+/// so they can't be removed individually later on.
+/// Add those that need to be removed to the built store instead,
+/// using [`Store::add_middleware`](struct.Store.html#method.add_middleware) or [`Store::subscribe`](struct.Store.html#method.subscribe).
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::Store;
+/// #
+/// type State = i8;
+///
+/// enum Action {
+///     Increment,
+///     Decrement
+/// }
+///
+/// fn reducer(state: &State, action: &Action) -> State {
+///     match action {
+///         Action::Increment => state + 1,
+///         Action::Decrement => state - 1
+///     }
+/// }
+///
+/// fn shall_not_decrement_middleware(_: &mut Store<State, Action>, action: Action) -> Option<Action> {
+///     match action {
+///         Action::Increment => Some(action),
+///         Action::Decrement => None
+///     }
+/// }
+///
+/// let mut store = Store::builder(reducer, 0)
+///     .middleware(shall_not_decrement_middleware)
+///     .subscription(|state: &State| println!("Something changed! New value: {}", state))
+///     .build();
+/// ```
+pub struct StoreBuilder<State, Action> {
+    store: Store<State, Action>
+}
+
+impl<State, Action> StoreBuilder<State, Action> {
+    /// Creates a new builder for a store.
+    ///
+    /// See [`Store::new`](struct.Store.html#method.new).
+    pub fn new(reducer: Reducer<State, Action>, initial_state: State) -> Self {
+        Self {
+            store: Store::new(reducer, initial_state)
+        }
+    }
+
+    /// Creates a new builder for a store with a reducer modifying the state in place.
+    ///
+    /// See [`Store::new_mut`](struct.Store.html#method.new_mut).
+    pub fn new_mut(reducer: ReducerMut<State, Action>, initial_state: State) -> Self {
+        Self {
+            store: Store::new_mut(reducer, initial_state)
+        }
+    }
+
+    /// Sets the preprocessor of the store.
+    ///
+    /// See [`Store::set_preprocessor`](struct.Store.html#method.set_preprocessor).
+    pub fn preprocessor(mut self, preprocessor: Preprocessor<Action>) -> Self {
+        self.store.set_preprocessor(preprocessor);
+        self
+    }
+
+    /// Adds a custom middleware to the store.
+    ///
+    /// See [`Store::add_middleware`](struct.Store.html#method.add_middleware).
+    pub fn middleware(mut self, middleware: Middleware<State, Action>) -> Self {
+        self.store.add_middleware(middleware);
+        self
+    }
+
+    /// Subscribes a callback to any change of the state.
+    ///
+    /// See [`Store::subscribe`](struct.Store.html#method.subscribe).
+    pub fn subscription(mut self, callback: Subscription<State>) -> Self {
+        self.store.subscribe(callback);
+        self
+    }
+
+    /// Subscribes a callback to any change of the state, with a priority.
+    ///
+    /// See [`Store::subscribe_with_priority`](struct.Store.html#method.subscribe_with_priority).
+    pub fn subscription_with_priority(
+        mut self,
+        priority: i32,
+        callback: Subscription<State>
+    ) -> Self {
+        self.store.subscribe_with_priority(priority, callback);
+        self
+    }
+
+    /// Subscribes a callback to any change of the state, as part of a named group.
+    ///
+    /// See [`Store::subscribe_to_group`](struct.Store.html#method.subscribe_to_group).
+    pub fn group_subscription(
+        mut self,
+        group: &'static str,
+        callback: Subscription<State>
+    ) -> Self {
+        self.store.subscribe_to_group(group, callback);
+        self
+    }
+
+    /// Isolates panicking subscriptions, passing their panics to a handler instead.
+    ///
+    /// See [`Store::set_panic_handler`](struct.Store.html#method.set_panic_handler).
+    #[cfg(feature = "std")]
+    pub fn panic_handler(mut self, handler: fn(SubscriptionId, Box<dyn Any + Send>)) -> Self {
+        self.store.set_panic_handler(handler);
+        self
+    }
+
+    /// Measures the time spent in the reducer and in subscriptions.
+    ///
+    /// See [`Store::enable_timings`](struct.Store.html#method.enable_timings).
+    #[cfg(feature = "std")]
+    pub fn timings(mut self) -> Self {
+        self.store.enable_timings();
+        self
+    }
+
+    /// Creates the configured store.
+    pub fn build(self) -> Store<State, Action> {
+        self.store
+    }
+}
@@ -134,22 +134,38 @@
 //! ```
 
 #![cfg_attr(not(feature = "std"), no_std)]
-#![cfg_attr(not(feature = "std"), feature(alloc))]
 
 #[cfg(not(feature = "std"))]
 extern crate alloc;
 #[cfg(not(feature = "std"))]
-use alloc::vec::Vec;
+use alloc::{
+    collections::{BTreeMap, VecDeque},
+    vec::Vec
+};
 #[cfg(feature = "std")]
-use std::vec::Vec;
+use std::{
+    collections::{BTreeMap, VecDeque},
+    vec::Vec
+};
 
+mod batch;
+mod builder;
+mod metrics;
 mod middleware;
+mod preprocessor;
 mod reducer;
 mod store;
 mod subscription;
+mod tenant;
+mod transaction;
 
-pub use middleware::Middleware;
-pub use reducer::Reducer;
-#[cfg(not(feature = "devtools"))]
+pub use batch::Batch;
+pub use builder::StoreBuilder;
+pub use metrics::Metrics;
+pub use middleware::{Middleware, MiddlewareId};
+pub use preprocessor::Preprocessor;
+pub use reducer::{Reducer, ReducerMut};
 pub use store::Store;
-pub use subscription::Subscription;
+pub use subscription::{Subscription, SubscriptionId};
+pub use tenant::TenantStore;
+pub use transaction::Transaction;
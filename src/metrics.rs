@@ -0,0 +1,47 @@
+#[cfg(feature = "std")]
+use std::time::Duration;
+
+/// Statistics collected by a store while dispatching actions.
+///
+/// The counters start at zero when the store is created and only ever grow.
+/// Timings are only available with the `std` feature, as measuring them requires a clock,
+/// and stay at zero unless enabled via [`Store::enable_timings`](struct.Store.html#method.enable_timings).
+///
+/// Only totals are collected, no histograms or percentiles.
+/// Averages can be derived by dividing a total by the matching counter.
+///
+/// More statistics may be added later, so outside of this crate the struct can only be created through `Default`.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::Store;
+/// #
+/// # fn reducer(state: &u8, _: &bool) -> u8 {
+/// #     state + 1
+/// # }
+/// #
+/// let mut store = Store::new(reducer, 0);
+/// store.dispatch(true);
+///
+/// let metrics = store.metrics();
+/// println!("Dispatched {} actions", metrics.actions_dispatched);
+/// # #[cfg(feature = "std")]
+/// println!("Spent {:?} reducing", metrics.reduce_time);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Metrics {
+    /// Number of actions passed to the store, including the ones halted by middleware.
+    pub actions_dispatched: u64,
+    /// Number of actions which reached the reducer.
+    pub actions_reduced: u64,
+    /// Highest number of actions buffered at once while the store was paused.
+    pub max_pending_actions: usize,
+    /// Total time spent in the reducer, if timings are enabled.
+    #[cfg(feature = "std")]
+    pub reduce_time: Duration,
+    /// Total time spent running subscriptions, if timings are enabled.
+    #[cfg(feature = "std")]
+    pub subscriber_time: Duration
+}
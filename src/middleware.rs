@@ -41,3 +41,106 @@ use crate::Store;
 /// store.add_middleware(shall_not_increment_middleware);
 /// ```
 pub type Middleware<State, Action> = fn(&mut Store<State, Action>, Action) -> Option<Action>;
+
+/// Identifies a middleware added to a store, so it can be removed again.
+///
+/// See [`Store::remove_middleware`](struct.Store.html#method.remove_middleware).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MiddlewareId(pub(crate) usize);
+
+#[macro_export]
+/// Creates a middleware which only lets actions matching a predicate through.
+///
+/// Actions for which the predicate returns `false` are dropped before they reach the reducer.
+/// The predicate receives a reference to the action and must not capture anything, so the result can be used as a [`Middleware`](type.Middleware.html).
+/// See [`filter_middleware_with_state!`](macro.filter_middleware_with_state.html) for a predicate which also receives the current state.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{filter_middleware, Store};
+/// #
+/// type State = u8;
+///
+/// enum Action {
+///     Increment,
+///     Reset
+/// }
+///
+/// fn reducer(state: &State, action: &Action) -> State {
+///     match action {
+///         Action::Increment => state + 1,
+///         Action::Reset => 0
+///     }
+/// }
+///
+/// let mut store = Store::new(reducer, 0);
+/// store.add_middleware(filter_middleware!(State, Action, |action: &Action| match action {
+///     Action::Increment => true,
+///     Action::Reset => false
+/// }));
+///
+/// store.dispatch(Action::Increment);
+/// store.dispatch(Action::Reset);
+/// assert_eq!(*store.state(), 1);
+/// ```
+macro_rules! filter_middleware {
+    ($state:ty, $action:ty, $predicate:expr) => {
+        |_: &mut $crate::Store<$state, $action>, action: $action| -> Option<$action> {
+            if ($predicate)(&action) {
+                Some(action)
+            } else {
+                None
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Creates a middleware which only lets actions matching a predicate on the current state through.
+///
+/// Works like [`filter_middleware!`](macro.filter_middleware.html), but the predicate receives a reference to the current state in addition to the action.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{filter_middleware_with_state, Store};
+/// #
+/// # type State = u8;
+/// #
+/// # enum Action {
+/// #     Increment,
+/// #     Reset
+/// # }
+/// #
+/// # fn reducer(state: &State, action: &Action) -> State {
+/// #     match action {
+/// #         Action::Increment => state + 1,
+/// #         Action::Reset => 0
+/// #     }
+/// # }
+/// #
+/// let mut store = Store::new(reducer, 0);
+///
+/// // Never count past three.
+/// store.add_middleware(filter_middleware_with_state!(State, Action, |state: &State, action: &Action| match action {
+///     Action::Increment => *state < 3,
+///     Action::Reset => true
+/// }));
+///
+/// for _ in 0..5 {
+///     store.dispatch(Action::Increment);
+/// }
+/// assert_eq!(*store.state(), 3);
+/// ```
+macro_rules! filter_middleware_with_state {
+    ($state:ty, $action:ty, $predicate:expr) => {
+        |store: &mut $crate::Store<$state, $action>, action: $action| -> Option<$action> {
+            if ($predicate)(store.state(), &action) {
+                Some(action)
+            } else {
+                None
+            }
+        }
+    };
+}
@@ -0,0 +1,33 @@
+/// Function signature for a preprocessor.
+///
+/// A preprocessor canonicalizes actions (e.g. trimming strings or clamping numbers) before they get dispatched.
+/// It is applied before the middleware, so both the middleware and the reducer only ever see canonical actions.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{Preprocessor, Store};
+/// #
+/// type State = u8;
+///
+/// enum Action {
+///     SetVolume(u8)
+/// }
+///
+/// fn reducer(_: &State, action: &Action) -> State {
+///     match action {
+///         Action::SetVolume(volume) => *volume
+///     }
+/// }
+///
+/// let clamp_volume: Preprocessor<Action> = |action: Action| match action {
+///     Action::SetVolume(volume) => Action::SetVolume(volume.min(100))
+/// };
+///
+/// let mut store = Store::new(reducer, 0);
+/// store.set_preprocessor(clamp_volume);
+///
+/// store.dispatch(Action::SetVolume(200));
+/// assert_eq!(*store.state(), 100);
+/// ```
+pub type Preprocessor<Action> = fn(Action) -> Action;
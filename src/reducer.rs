@@ -82,3 +82,27 @@ macro_rules! combine_reducers {
         }
     )
 }
+
+/// Function signature for a reducer which modifies the state in place.
+///
+/// Instead of creating a new state from the old one, it receives a mutable reference to the state.
+/// This avoids moving large states around on every action, at the cost of the state no longer being immutable from the reducer's point of view.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::ReducerMut;
+/// #
+/// enum Action {
+///     Push(u8),
+///     Clear
+/// }
+///
+/// let reducer: ReducerMut<Vec<u8>, Action> = |state: &mut Vec<u8>, action: &Action| {
+///     match action {
+///         Action::Push(value) => state.push(*value),
+///         Action::Clear => state.clear()
+///     }
+/// };
+/// ```
+pub type ReducerMut<State, Action> = fn(&mut State, &Action);
@@ -1,13 +1,146 @@
-use crate::{Middleware, Reducer, Subscription, Vec};
+use crate::{
+    Batch, Metrics, Middleware, MiddlewareId, Preprocessor, Reducer, ReducerMut, StoreBuilder,
+    Subscription, SubscriptionId, Transaction, Vec, VecDeque
+};
+use core::{
+    borrow::Borrow,
+    mem,
+    ops::{Deref, DerefMut}
+};
+#[cfg(feature = "std")]
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    time::Instant
+};
 
 /// A container holding a state and providing the possibility to dispatch actions.
 ///
 /// A store is defined by the state is holds and the actions it can dispatch.
 pub struct Store<State, Action> {
-    reducer: Reducer<State, Action>,
+    reducer: StoreReducer<State, Action>,
     state: State,
-    middleware: Vec<Middleware<State, Action>>,
-    subscriptions: Vec<Subscription<State>>
+    preprocessor: Option<Preprocessor<Action>>,
+    middleware: Vec<(MiddlewareId, Middleware<State, Action>)>,
+    next_middleware_id: usize,
+    subscriptions: Vec<SubscriptionEntry<State>>,
+    next_subscription_id: usize,
+    paused_groups: Vec<&'static str>,
+    batching: bool,
+    batch_changed: bool,
+    paused: bool,
+    pending: VecDeque<Pending<Action>>,
+    pending_len: usize,
+    #[cfg(feature = "std")]
+    panic_handler: Option<fn(SubscriptionId, Box<dyn Any + Send>)>,
+    #[cfg(feature = "std")]
+    timings: bool,
+    metrics: Metrics
+}
+
+/// The reducer of a store, either creating a new state or modifying it in place.
+enum StoreReducer<State, Action> {
+    Pure(Reducer<State, Action>),
+    InPlace(ReducerMut<State, Action>)
+}
+
+/// Work buffered while a store is paused: a single action or a committed batch, dispatched as one unit.
+enum Pending<Action> {
+    Action(Action),
+    Batch(Vec<Action>)
+}
+
+/// Marks a store as batching while alive.
+///
+/// Dropping the guard restores the previous batching state, even when unwinding from a panic,
+/// so a panicking reducer or middleware cannot leave the store without subscription calls.
+struct BatchingGuard<'a, State, Action> {
+    store: &'a mut Store<State, Action>,
+    previous: bool
+}
+
+impl<'a, State, Action> BatchingGuard<'a, State, Action> {
+    fn new(store: &'a mut Store<State, Action>) -> Self {
+        let previous = mem::replace(&mut store.batching, true);
+
+        Self { store, previous }
+    }
+}
+
+impl<State, Action> Deref for BatchingGuard<'_, State, Action> {
+    type Target = Store<State, Action>;
+
+    fn deref(&self) -> &Self::Target {
+        self.store
+    }
+}
+
+impl<State, Action> DerefMut for BatchingGuard<'_, State, Action> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.store
+    }
+}
+
+impl<State, Action> Drop for BatchingGuard<'_, State, Action> {
+    fn drop(&mut self) {
+        self.store.batching = self.previous;
+
+        if !self.previous {
+            self.store.batch_changed = false;
+        }
+    }
+}
+
+/// Restores the state of a store to a snapshot when dropped, unless the snapshot was discarded.
+///
+/// Used by transactions, so an `Err` or a panic halfway through rolls back every action applied so far.
+struct RollbackGuard<'a, State, Action> {
+    store: &'a mut Store<State, Action>,
+    snapshot: Option<(State, bool)>
+}
+
+impl<'a, State: Clone, Action> RollbackGuard<'a, State, Action> {
+    fn new(store: &'a mut Store<State, Action>) -> Self {
+        let snapshot = Some((store.state.clone(), store.batch_changed));
+
+        Self { store, snapshot }
+    }
+
+    /// Keeps the current state, discarding the snapshot.
+    fn keep(mut self) {
+        self.snapshot = None;
+    }
+}
+
+impl<State, Action> Deref for RollbackGuard<'_, State, Action> {
+    type Target = Store<State, Action>;
+
+    fn deref(&self) -> &Self::Target {
+        self.store
+    }
+}
+
+impl<State, Action> DerefMut for RollbackGuard<'_, State, Action> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.store
+    }
+}
+
+impl<State, Action> Drop for RollbackGuard<'_, State, Action> {
+    fn drop(&mut self) {
+        if let Some((state, changed)) = self.snapshot.take() {
+            self.store.state = state;
+            self.store.batch_changed = changed;
+        }
+    }
+}
+
+/// A subscription together with its id, the group it belongs to, if any, and its priority.
+struct SubscriptionEntry<State> {
+    id: SubscriptionId,
+    callback: Subscription<State>,
+    group: Option<&'static str>,
+    priority: i32
 }
 
 impl<State, Action> Store<State, Action> {
@@ -35,14 +168,119 @@ impl<State, Action> Store<State, Action> {
     /// let mut store = Store::new(reducer, 0);
     /// ```
     pub fn new(reducer: Reducer<State, Action>, initial_state: State) -> Self {
+        Self::with_reducer(StoreReducer::Pure(reducer), initial_state)
+    }
+
+    /// Creates a new store with a reducer modifying the state in place.
+    ///
+    /// See [`ReducerMut`](type.ReducerMut.html).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// enum Action {
+    ///     Push(u8),
+    ///     Clear
+    /// }
+    ///
+    /// fn reducer(state: &mut Vec<u8>, action: &Action) {
+    ///     match action {
+    ///         Action::Push(value) => state.push(*value),
+    ///         Action::Clear => state.clear()
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new_mut(reducer, Vec::new());
+    /// store.dispatch(Action::Push(1));
+    /// ```
+    pub fn new_mut(reducer: ReducerMut<State, Action>, initial_state: State) -> Self {
+        Self::with_reducer(StoreReducer::InPlace(reducer), initial_state)
+    }
+
+    /// Creates a new store with either kind of reducer.
+    fn with_reducer(reducer: StoreReducer<State, Action>, initial_state: State) -> Self {
         Self {
             reducer,
             state: initial_state,
+            preprocessor: None,
             middleware: Vec::new(),
-            subscriptions: Vec::new()
+            next_middleware_id: 0,
+            subscriptions: Vec::new(),
+            next_subscription_id: 0,
+            paused_groups: Vec::new(),
+            batching: false,
+            batch_changed: false,
+            paused: false,
+            pending: VecDeque::new(),
+            pending_len: 0,
+            #[cfg(feature = "std")]
+            panic_handler: None,
+            #[cfg(feature = "std")]
+            timings: false,
+            metrics: Metrics::default()
         }
     }
 
+    /// Creates a builder to configure a new store.
+    ///
+    /// See [`StoreBuilder`](struct.StoreBuilder.html).
+    pub fn builder(
+        reducer: Reducer<State, Action>,
+        initial_state: State
+    ) -> StoreBuilder<State, Action> {
+        StoreBuilder::new(reducer, initial_state)
+    }
+
+    /// Creates a builder to configure a new store with a reducer modifying the state in place.
+    ///
+    /// See [`StoreBuilder`](struct.StoreBuilder.html) and [`new_mut`](#method.new_mut).
+    pub fn builder_mut(
+        reducer: ReducerMut<State, Action>,
+        initial_state: State
+    ) -> StoreBuilder<State, Action> {
+        StoreBuilder::new_mut(reducer, initial_state)
+    }
+
+    /// Feeds a recorded sequence of actions through a reducer, starting from an initial state, and returns the final state.
+    ///
+    /// Only the reducer is involved: there is no middleware and there are no subscriptions, so replaying is deterministic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # type State = i8;
+    /// #
+    /// # enum Action {
+    /// #     Increment,
+    /// #     Decrement
+    /// # }
+    /// #
+    /// # fn reducer(state: &State, action: &Action) -> State {
+    /// #     match action {
+    /// #         Action::Increment => state + 1,
+    /// #         Action::Decrement => state - 1
+    /// #     }
+    /// # }
+    /// #
+    /// let recorded = vec![Action::Increment, Action::Increment, Action::Decrement];
+    ///
+    /// let state = Store::replay(reducer, 0, &recorded);
+    /// assert_eq!(state, 1);
+    /// ```
+    pub fn replay<I>(reducer: Reducer<State, Action>, initial_state: State, actions: I) -> State
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Action>
+    {
+        actions.into_iter().fold(initial_state, |state, action| {
+            reducer(&state, action.borrow())
+        })
+    }
+
     /// Returns the current state.
     ///
     /// # Example
@@ -58,6 +296,49 @@ impl<State, Action> Store<State, Action> {
         &self.state
     }
 
+    /// Returns the statistics collected while dispatching actions.
+    ///
+    /// See [`Metrics`](struct.Metrics.html).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # let store = Store::new(|&u8, ()| 0, 0);
+    /// #
+    /// println!("Actions dispatched: {}", store.metrics().actions_dispatched);
+    /// ```
+    pub fn metrics(&self) -> &Metrics {
+        &self.metrics
+    }
+
+    /// Starts measuring the time spent in the reducer and in subscriptions, as part of the [`Metrics`](struct.Metrics.html).
+    ///
+    /// Timings are disabled by default, as reading the clock on every dispatch is expensive compared to a typical reducer.
+    ///
+    /// Only available with the `std` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, _: &bool) -> u8 {
+    /// #     state + 1
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    /// store.enable_timings();
+    /// store.dispatch(true);
+    ///
+    /// println!("Spent {:?} reducing", store.metrics().reduce_time);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn enable_timings(&mut self) {
+        self.timings = true;
+    }
+
     /// Dispatches an action which is handles by the reducer, after the store got passed through the middleware.
     /// This can modify the state within the store.
     ///
@@ -85,42 +366,431 @@ impl<State, Action> Store<State, Action> {
     /// println!("Current state: {}", store.state());
     /// ```
     pub fn dispatch(&mut self, action: Action) {
-        if self.middleware.is_empty() {
-            self.dispatch_reducer(&action);
+        let action = self.preprocess(action);
+
+        if self.paused {
+            self.buffer(Pending::Action(action), 1);
         } else {
-            self.dispatch_middleware(0, action);
+            self.dispatch_action(action);
         }
     }
 
-    /// Runs one middleware.
-    fn dispatch_middleware(&mut self, index: usize, action: Action) {
-        if index == self.middleware.len() {
+    /// Counts an incoming action and applies the preprocessor to it.
+    fn preprocess(&mut self, action: Action) -> Action {
+        self.metrics.actions_dispatched += 1;
+
+        match self.preprocessor {
+            Some(preprocessor) => preprocessor(action),
+            None => action
+        }
+    }
+
+    /// Buffers work while the store is paused.
+    fn buffer(&mut self, pending: Pending<Action>, len: usize) {
+        self.pending.push_back(pending);
+        self.pending_len += len;
+        self.metrics.max_pending_actions = self.metrics.max_pending_actions.max(self.pending_len);
+    }
+
+    /// Dispatches the oldest buffered unit of work, returning `false` if nothing was buffered.
+    fn dispatch_pending(&mut self) -> bool {
+        match self.pending.pop_front() {
+            Some(Pending::Action(action)) => {
+                self.pending_len -= 1;
+                self.dispatch_action(action);
+            }
+            Some(Pending::Batch(actions)) => {
+                self.pending_len -= actions.len();
+                self.batched(|store| {
+                    for action in actions {
+                        store.dispatch_action(action);
+                    }
+                });
+            }
+            None => return false
+        }
+
+        true
+    }
+
+    /// Pauses processing actions.
+    ///
+    /// Actions dispatched while the store is paused are buffered, without passing through the middleware or reaching the reducer.
+    /// They get dispatched in order as soon as the store is resumed.
+    ///
+    /// A [batch](struct.Batch.html) committed while the store is paused is buffered as a whole, in the order it was committed in,
+    /// and still applied as one unit when the store is resumed.
+    /// A [transaction](#method.transaction) can't wait, so it dispatches the buffered actions first and is then applied immediately.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, _: &bool) -> u8 {
+    /// #     state + 1
+    /// # }
+    /// #
+    /// # let mut store = Store::new(reducer, 0);
+    /// #
+    /// store.pause();
+    /// store.dispatch(true);
+    /// assert_eq!(*store.state(), 0);
+    ///
+    /// store.resume();
+    /// assert_eq!(*store.state(), 1);
+    /// ```
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Resumes processing actions, dispatching all actions buffered while the store was paused.
+    pub fn resume(&mut self) {
+        self.paused = false;
+
+        while !self.paused && self.dispatch_pending() {}
+    }
+
+    /// Resumes processing actions like [`resume`](#method.resume), but calls the subscriptions only once after all buffered actions got dispatched.
+    ///
+    /// This avoids notifying subscriptions about every intermediate state when many actions were buffered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, _: &bool) -> u8 {
+    /// #     state + 1
+    /// # }
+    /// #
+    /// # let mut store = Store::new(reducer, 0);
+    /// #
+    /// store.subscribe(|state: &u8| {
+    ///     // Only called once, with the final state.
+    ///     assert_eq!(*state, 3);
+    /// });
+    ///
+    /// store.pause();
+    /// store.dispatch(true);
+    /// store.dispatch(true);
+    /// store.dispatch(true);
+    /// store.resume_batched();
+    /// ```
+    pub fn resume_batched(&mut self) {
+        self.batched(Self::resume);
+    }
+
+    /// Returns the number of actions buffered while the store is paused.
+    ///
+    /// Every action of a buffered batch counts.
+    ///
+    /// The highest number of buffered actions so far is part of the [`Metrics`](struct.Metrics.html).
+    pub fn pending_actions(&self) -> usize {
+        self.pending_len
+    }
+
+    /// Returns `true` if the store is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Passes an action through the middleware and the reducer.
+    fn dispatch_action(&mut self, action: Action) {
+        if self.middleware.is_empty() {
             self.dispatch_reducer(&action);
-            return;
+        } else {
+            let last = self.next_middleware_id;
+            self.dispatch_middleware(0, last, action);
         }
+    }
+
+    /// Runs the first middleware with an id in `first..last`.
+    ///
+    /// The chain is walked by id rather than by index,
+    /// so middleware added or removed by a middleware doesn't shift the chain of the action in flight:
+    /// removed middleware is skipped and added middleware only sees the next action.
+    fn dispatch_middleware(&mut self, first: usize, last: usize, action: Action) {
+        let index = self.middleware.partition_point(|(id, _)| id.0 < first);
+
+        let (id, middleware) = match self.middleware.get(index) {
+            Some(&(id, middleware)) if id.0 < last => (id, middleware),
+            _ => {
+                self.dispatch_reducer(&action);
+                return;
+            }
+        };
 
-        let next = self.middleware[index](self, action);
+        let next = middleware(self, action);
 
         if next.is_none() {
             return;
         }
 
-        self.dispatch_middleware(index + 1, next.unwrap());
+        self.dispatch_middleware(id.0 + 1, last, next.unwrap());
     }
 
     /// Runs the reducer.
     fn dispatch_reducer(&mut self, action: &Action) {
-        self.state = (&self.reducer)(self.state(), action);
-        self.dispatch_subscriptions();
+        #[cfg(feature = "std")]
+        let start = self.timings.then(Instant::now);
+
+        match self.reducer {
+            StoreReducer::Pure(reducer) => self.state = reducer(self.state(), action),
+            StoreReducer::InPlace(reducer) => reducer(&mut self.state, action)
+        }
+        self.metrics.actions_reduced += 1;
+
+        #[cfg(feature = "std")]
+        {
+            if let Some(start) = start {
+                self.metrics.reduce_time += start.elapsed();
+            }
+        }
+
+        if self.batching {
+            self.batch_changed = true;
+        } else if !self.subscriptions.is_empty() {
+            self.dispatch_subscriptions();
+        }
+    }
+
+    /// Dispatches multiple actions, calling the subscriptions only once afterwards.
+    ///
+    /// Subscriptions are not called if none of the actions reached the reducer.
+    /// While the store is paused, the actions are buffered as one unit.
+    pub(crate) fn dispatch_batch(&mut self, actions: Vec<Action>) {
+        if self.paused {
+            let actions: Vec<Action> = actions
+                .into_iter()
+                .map(|action| self.preprocess(action))
+                .collect();
+            let len = actions.len();
+
+            if len > 0 {
+                self.buffer(Pending::Batch(actions), len);
+            }
+
+            return;
+        }
+
+        self.batched(|store| {
+            for action in actions {
+                store.dispatch_now(action);
+            }
+        });
+    }
+
+    /// Dispatches an action right away, even while the store is paused.
+    pub(crate) fn dispatch_now(&mut self, action: Action) {
+        let action = self.preprocess(action);
+        self.dispatch_action(action);
+    }
+
+    /// Runs a function on the store, calling the subscriptions only once afterwards if any action reached the reducer.
+    fn batched<T, F: FnOnce(&mut Self) -> T>(&mut self, f: F) -> T {
+        let mut guard = BatchingGuard::new(self);
+
+        let value = f(&mut guard);
+
+        let changed = !guard.previous && mem::take(&mut guard.batch_changed);
+        drop(guard);
+
+        if changed {
+            self.dispatch_subscriptions();
+        }
+
+        value
+    }
+
+    /// Starts a batch of actions.
+    ///
+    /// Actions dispatched through the batch are buffered and only dispatched once the batch gets committed.
+    /// The subscriptions are called a single time for the whole batch.
+    /// Dropping the batch without committing it discards the buffered actions.
+    ///
+    /// See [`Batch`](struct.Batch.html).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, _: &bool) -> u8 {
+    /// #     state + 1
+    /// # }
+    /// #
+    /// # let mut store = Store::new(reducer, 0);
+    /// #
+    /// let mut batch = store.begin_batch();
+    /// batch.dispatch(true);
+    /// batch.dispatch(false);
+    /// batch.commit();
+    ///
+    /// assert_eq!(*store.state(), 2);
+    /// ```
+    pub fn begin_batch(&mut self) -> Batch<'_, State, Action> {
+        Batch::new(self)
+    }
+
+    /// Dispatches the actions of a transaction at once, or none of them.
+    ///
+    /// The function receives a [`Transaction`](struct.Transaction.html) to dispatch actions through.
+    /// Each action is reduced right away, so [`Transaction::state`](struct.Transaction.html#method.state) reflects the actions dispatched so far.
+    /// The subscriptions are not called while the function runs.
+    ///
+    /// If the function returns `Ok`, the changes are kept and the subscriptions are called once.
+    /// If it returns `Err` or panics, including a panic in the reducer or a middleware,
+    /// the state is restored to a snapshot taken before the transaction and the subscriptions are not called.
+    /// Side effects of middleware are not rolled back.
+    ///
+    /// A transaction is applied immediately, even while the store is [paused](#method.pause).
+    /// To keep the actions in order, the actions buffered while paused are dispatched first; the store stays paused.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # type State = i8;
+    /// #
+    /// # enum Action {
+    /// #     Increment,
+    /// #     Decrement
+    /// # }
+    /// #
+    /// # fn reducer(state: &State, action: &Action) -> State {
+    /// #     match action {
+    /// #         Action::Increment => state + 1,
+    /// #         Action::Decrement => state - 1
+    /// #     }
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    ///
+    /// let result = store.transaction(|tx| {
+    ///     tx.dispatch(Action::Decrement);
+    ///     tx.dispatch(Action::Decrement);
+    ///
+    ///     if *tx.state() < 0 {
+    ///         return Err("The counter must not become negative");
+    ///     }
+    ///
+    ///     Ok(())
+    /// });
+    ///
+    /// assert!(result.is_err());
+    /// assert_eq!(*store.state(), 0);
+    /// ```
+    pub fn transaction<T, E, F>(&mut self, f: F) -> Result<T, E>
+    where
+        State: Clone,
+        F: FnOnce(&mut Transaction<State, Action>) -> Result<T, E>
+    {
+        if self.paused {
+            while self.dispatch_pending() {}
+        }
+
+        self.batched(|store| {
+            let mut guard = RollbackGuard::new(store);
+
+            let result = f(&mut Transaction::new(&mut guard));
+
+            if result.is_ok() {
+                guard.keep();
+            }
+
+            result
+        })
     }
 
     /// Runs all subscriptions.
-    fn dispatch_subscriptions(&self) {
-        for subscription in &self.subscriptions {
-            subscription(self.state());
+    fn dispatch_subscriptions(&mut self) {
+        #[cfg(feature = "std")]
+        let start = self.timings.then(Instant::now);
+
+        let mut index = 0;
+
+        while let Some(subscription) = self.subscriptions.get(index) {
+            let paused = subscription
+                .group
+                .is_some_and(|group| self.paused_groups.contains(&group));
+
+            if paused || self.call_subscription(subscription.id, subscription.callback) {
+                index += 1;
+            } else {
+                self.subscriptions.remove(index);
+            }
+        }
+
+        #[cfg(feature = "std")]
+        {
+            if let Some(start) = start {
+                self.metrics.subscriber_time += start.elapsed();
+            }
         }
     }
 
+    /// Calls a subscription, returning `false` if it panicked and the panic got passed to the panic handler.
+    #[cfg(feature = "std")]
+    fn call_subscription(&self, id: SubscriptionId, callback: Subscription<State>) -> bool {
+        let handler = match self.panic_handler {
+            Some(handler) => handler,
+            None => {
+                callback(self.state());
+                return true;
+            }
+        };
+
+        match panic::catch_unwind(AssertUnwindSafe(|| callback(self.state()))) {
+            Ok(()) => true,
+            Err(payload) => {
+                handler(id, payload);
+                false
+            }
+        }
+    }
+
+    /// Calls a subscription.
+    #[cfg(not(feature = "std"))]
+    fn call_subscription(&self, _: SubscriptionId, callback: Subscription<State>) -> bool {
+        callback(self.state());
+        true
+    }
+
+    /// Isolates panicking subscriptions, passing their panics to a handler instead.
+    ///
+    /// By default, a panic in a subscription propagates to the caller of `dispatch`, skipping the remaining subscriptions.
+    /// With a panic handler, the panic is caught, the offending subscription gets removed and the handler receives its id and the panic payload.
+    /// The remaining subscriptions are still called.
+    ///
+    /// Only available with the `std` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, _: &bool) -> u8 {
+    /// #     state + 1
+    /// # }
+    /// #
+    /// # let mut store = Store::new(reducer, 0);
+    /// #
+    /// store.set_panic_handler(|id, payload| {
+    ///     eprintln!("Subscription {:?} panicked: {:?}", id, payload.downcast_ref::<&str>());
+    /// });
+    ///
+    /// store.subscribe(|_: &u8| panic!("Oops"));
+    ///
+    /// // Removes the panicking subscription instead of panicking.
+    /// store.dispatch(true);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn set_panic_handler(&mut self, handler: fn(SubscriptionId, Box<dyn Any + Send>)) {
+        self.panic_handler = Some(handler);
+    }
+
     /// Subscribes a callback to any change of the state.
     ///
     /// Subscriptions will be called, whenever an action is dispatched.
@@ -147,17 +817,265 @@ impl<State, Action> Store<State, Action> {
     ///
     /// store.subscribe(listener);
     /// ```
-    pub fn subscribe(&mut self, callback: Subscription<State>) {
-        self.subscriptions.push(callback);
+    pub fn subscribe(&mut self, callback: Subscription<State>) -> SubscriptionId {
+        self.insert_subscription(callback, None, 0)
+    }
+
+    /// Subscribes a callback to any change of the state, with a priority.
+    ///
+    /// Subscriptions with a higher priority are called before the ones with a lower priority.
+    /// Subscriptions with the same priority are called in the order they were added.
+    /// Subscriptions added via [`subscribe`](#method.subscribe) have a priority of `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # type State = u8;
+    /// #
+    /// # fn reducer(_: &State, action: &bool) -> State {
+    /// #     0
+    /// # }
+    /// #
+    /// # let mut store = Store::new(reducer, 0);
+    /// #
+    /// store.subscribe(|state: &State| {
+    ///     println!("Redraw! New value: {}", state);
+    /// });
+    ///
+    /// // Gets called before redrawing.
+    /// store.subscribe_with_priority(10, |state: &State| {
+    ///     println!("Persist! New value: {}", state);
+    /// });
+    /// ```
+    pub fn subscribe_with_priority(
+        &mut self,
+        priority: i32,
+        callback: Subscription<State>
+    ) -> SubscriptionId {
+        self.insert_subscription(callback, None, priority)
+    }
+
+    /// Subscribes a callback to any change of the state, as part of a named group.
+    ///
+    /// All subscriptions of a group can be paused and resumed at once, see [`pause_group`](#method.pause_group).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::{Store, Subscription};
+    /// #
+    /// # type State = u8;
+    /// #
+    /// # fn reducer(_: &State, action: &bool) -> State {
+    /// #     0
+    /// # }
+    /// #
+    /// # let mut store = Store::new(reducer, 0);
+    /// #
+    /// store.subscribe_to_group("ui", |state: &State| {
+    ///     println!("Redraw! New value: {}", state);
+    /// });
+    /// ```
+    pub fn subscribe_to_group(
+        &mut self,
+        group: &'static str,
+        callback: Subscription<State>
+    ) -> SubscriptionId {
+        self.insert_subscription(callback, Some(group), 0)
+    }
+
+    /// Inserts a subscription after all subscriptions with the same or a higher priority.
+    fn insert_subscription(
+        &mut self,
+        callback: Subscription<State>,
+        group: Option<&'static str>,
+        priority: i32
+    ) -> SubscriptionId {
+        let id = SubscriptionId(self.next_subscription_id);
+        self.next_subscription_id += 1;
+
+        let index = self
+            .subscriptions
+            .iter()
+            .position(|subscription| subscription.priority < priority)
+            .unwrap_or(self.subscriptions.len());
+
+        self.subscriptions.insert(
+            index,
+            SubscriptionEntry {
+                id,
+                callback,
+                group,
+                priority
+            }
+        );
+
+        id
+    }
+
+    /// Removes a subscription previously added to the store.
+    ///
+    /// Returns `false` if the subscription was already removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, _: &bool) -> u8 {
+    /// #     state + 1
+    /// # }
+    /// #
+    /// # let mut store = Store::new(reducer, 0);
+    /// #
+    /// let listener = store.subscribe(|state: &u8| {
+    ///     println!("Something changed! New value: {}", state);
+    /// });
+    ///
+    /// store.unsubscribe(listener);
+    /// ```
+    pub fn unsubscribe(&mut self, id: SubscriptionId) -> bool {
+        let len = self.subscriptions.len();
+        self.subscriptions
+            .retain(|subscription| subscription.id != id);
+        self.subscriptions.len() != len
+    }
+
+    /// Removes all subscriptions for which the predicate returns `true`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, _: &bool) -> u8 {
+    /// #     state + 1
+    /// # }
+    /// #
+    /// # let mut store = Store::new(reducer, 0);
+    /// #
+    /// let screen = vec![
+    ///     store.subscribe(|state: &u8| println!("Title: {}", state)),
+    ///     store.subscribe(|state: &u8| println!("Body: {}", state))
+    /// ];
+    ///
+    /// // Tear down the screen.
+    /// store.remove_subscriptions_where(|id| screen.contains(&id));
+    /// ```
+    pub fn remove_subscriptions_where<F: FnMut(SubscriptionId) -> bool>(
+        &mut self,
+        mut predicate: F
+    ) {
+        self.subscriptions
+            .retain(|subscription| !predicate(subscription.id));
+    }
+
+    /// Removes all subscriptions.
+    pub fn clear_subscriptions(&mut self) {
+        self.subscriptions.clear();
+    }
+
+    /// Stops calling the subscriptions of a group until it gets resumed.
+    ///
+    /// This is useful when dispatching many actions in a row (e.g. during an import), where notifying every subscriber would be wasteful.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, _: &bool) -> u8 {
+    /// #     state + 1
+    /// # }
+    /// #
+    /// # let mut store = Store::new(reducer, 0);
+    /// #
+    /// store.subscribe_to_group("ui", |state: &u8| {
+    ///     println!("Redraw! New value: {}", state);
+    /// });
+    ///
+    /// store.pause_group("ui");
+    ///
+    /// for _ in 0..100 {
+    ///     store.dispatch(true);
+    /// }
+    ///
+    /// store.resume_group("ui");
+    /// ```
+    pub fn pause_group(&mut self, group: &'static str) {
+        if !self.paused_groups.contains(&group) {
+            self.paused_groups.push(group);
+        }
+    }
+
+    /// Resumes calling the subscriptions of a group paused by [`pause_group`](#method.pause_group).
+    ///
+    /// Subscriptions are not called retroactively for the actions dispatched while the group was paused.
+    pub fn resume_group(&mut self, group: &'static str) {
+        self.paused_groups.retain(|paused| *paused != group);
+    }
+
+    /// Sets the preprocessor of the store, replacing the previous one.
+    ///
+    /// The preprocessor is applied to every dispatched action before anything else, including the middleware.
+    ///
+    /// See [`Preprocessor`](type.Preprocessor.html).
+    pub fn set_preprocessor(&mut self, preprocessor: Preprocessor<Action>) {
+        self.preprocessor = Some(preprocessor);
+    }
+
+    /// Removes the preprocessor of the store, if any.
+    pub fn clear_preprocessor(&mut self) {
+        self.preprocessor = None;
     }
 
     /// Adds a custom middleware to the store.
     ///
     /// Middleware provides the possibility to intercept actions dispatched before they reach the reducer.
     ///
+    /// The returned id can be used to remove the middleware again.
+    ///
     /// See [`Middleware`](type.Middleware.html).
-    pub fn add_middleware(&mut self, middleware: Middleware<State, Action>) {
-        self.middleware.push(middleware);
+    pub fn add_middleware(&mut self, middleware: Middleware<State, Action>) -> MiddlewareId {
+        let id = MiddlewareId(self.next_middleware_id);
+        self.next_middleware_id += 1;
+        self.middleware.push((id, middleware));
+        id
+    }
+
+    /// Removes a middleware previously added to the store.
+    ///
+    /// Returns `false` if the middleware was already removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, _: &bool) -> u8 {
+    /// #     state + 1
+    /// # }
+    /// #
+    /// fn logging_middleware(store: &mut Store<u8, bool>, action: bool) -> Option<bool> {
+    ///     println!("Dispatching {} on {}", action, store.state());
+    ///     Some(action)
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, 0);
+    ///
+    /// let logging = store.add_middleware(logging_middleware);
+    /// store.dispatch(true);
+    ///
+    /// store.remove_middleware(logging);
+    /// store.dispatch(true);
+    /// ```
+    pub fn remove_middleware(&mut self, id: MiddlewareId) -> bool {
+        let len = self.middleware.len();
+        self.middleware
+            .retain(|(middleware_id, _)| *middleware_id != id);
+        self.middleware.len() != len
     }
 
     /// Replaces the currently used reducer.
@@ -194,6 +1112,13 @@ impl<State, Action> Store<State, Action> {
     /// store.dispatch(Action::SomeAction);
     /// ```
     pub fn replace_reducer(&mut self, reducer: Reducer<State, Action>) {
-        self.reducer = reducer;
+        self.reducer = StoreReducer::Pure(reducer);
+    }
+
+    /// Replaces the currently used reducer with one modifying the state in place.
+    ///
+    /// See [`ReducerMut`](type.ReducerMut.html).
+    pub fn replace_reducer_mut(&mut self, reducer: ReducerMut<State, Action>) {
+        self.reducer = StoreReducer::InPlace(reducer);
     }
 }
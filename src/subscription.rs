@@ -24,3 +24,9 @@
 /// store.subscribe(listener);
 /// ```
 pub type Subscription<State> = fn(&State);
+
+/// Identifies a subscription added to a store, so it can be removed again.
+///
+/// See [`Store::unsubscribe`](struct.Store.html#method.unsubscribe).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(pub(crate) usize);
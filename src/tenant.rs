@@ -0,0 +1,195 @@
+use crate::{BTreeMap, Middleware, MiddlewareId, Reducer, Store, Vec};
+
+/// A container holding one independent store per tenant, all sharing the same reducer.
+///
+/// Tenants are created lazily: the first time a tenant is used, its initial state is produced by the init function.
+/// Tenants can be evicted again, individually or by a predicate.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::TenantStore;
+/// #
+/// type State = u8;
+///
+/// enum Action {
+///     Increment,
+///     Decrement
+/// }
+///
+/// fn reducer(state: &State, action: &Action) -> State {
+///     match action {
+///         Action::Increment => state + 1,
+///         Action::Decrement => state - 1
+///     }
+/// }
+///
+/// let mut store = TenantStore::new(reducer, |_: &&str| 0);
+///
+/// store.dispatch("acme", Action::Increment);
+/// store.dispatch("acme", Action::Increment);
+/// store.dispatch("initech", Action::Increment);
+///
+/// assert_eq!(store.state(&"acme"), Some(&2));
+/// assert_eq!(store.state(&"initech"), Some(&1));
+/// assert_eq!(store.state(&"umbrella"), None);
+/// ```
+pub struct TenantStore<Key, State, Action> {
+    reducer: Reducer<State, Action>,
+    init: fn(&Key) -> State,
+    middleware: Vec<(MiddlewareId, Middleware<State, Action>)>,
+    next_middleware_id: usize,
+    tenants: BTreeMap<Key, Tenant<State, Action>>
+}
+
+/// The store of a tenant, together with the ids the shared middleware got in that store.
+struct Tenant<State, Action> {
+    store: Store<State, Action>,
+    middleware: Vec<(MiddlewareId, MiddlewareId)>
+}
+
+impl<Key: Ord, State, Action> TenantStore<Key, State, Action> {
+    /// Creates a new tenant store without any tenants.
+    ///
+    /// The init function is called with the key of a tenant to create its initial state.
+    pub fn new(reducer: Reducer<State, Action>, init: fn(&Key) -> State) -> Self {
+        Self {
+            reducer,
+            init,
+            middleware: Vec::new(),
+            next_middleware_id: 0,
+            tenants: BTreeMap::new()
+        }
+    }
+
+    /// Returns the current state of a tenant, or `None` if the tenant does not exist.
+    pub fn state(&self, key: &Key) -> Option<&State> {
+        self.tenants.get(key).map(|tenant| tenant.store.state())
+    }
+
+    /// Dispatches an action to the store of a tenant, creating the tenant if needed.
+    ///
+    /// See [`Store::dispatch`](struct.Store.html#method.dispatch).
+    pub fn dispatch(&mut self, key: Key, action: Action) {
+        self.tenant(key).dispatch(action);
+    }
+
+    /// Returns the store of a tenant, creating the tenant if needed.
+    ///
+    /// This gives access to everything a single store provides, like per-tenant subscriptions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::TenantStore;
+    /// #
+    /// # fn reducer(state: &u8, _: &bool) -> u8 {
+    /// #     state + 1
+    /// # }
+    /// #
+    /// let mut store = TenantStore::new(reducer, |_: &u32| 0);
+    ///
+    /// store.tenant(42).subscribe(|state: &u8| {
+    ///     println!("Tenant 42 changed! New value: {}", state);
+    /// });
+    /// ```
+    pub fn tenant(&mut self, key: Key) -> &mut Store<State, Action> {
+        let reducer = self.reducer;
+        let init = self.init;
+        let middleware = &self.middleware;
+
+        let tenant = self.tenants.entry(key).or_insert_with_key(|key| {
+            let mut store = Store::new(reducer, init(key));
+
+            let middleware = middleware
+                .iter()
+                .map(|&(id, middleware)| (id, store.add_middleware(middleware)))
+                .collect();
+
+            Tenant { store, middleware }
+        });
+
+        &mut tenant.store
+    }
+
+    /// Adds a custom middleware to the stores of all tenants, including the ones created later on.
+    ///
+    /// The returned id can be used to remove the middleware from all tenants again.
+    ///
+    /// See [`Middleware`](type.Middleware.html).
+    pub fn add_middleware(&mut self, middleware: Middleware<State, Action>) -> MiddlewareId {
+        let id = MiddlewareId(self.next_middleware_id);
+        self.next_middleware_id += 1;
+
+        for tenant in self.tenants.values_mut() {
+            let local = tenant.store.add_middleware(middleware);
+            tenant.middleware.push((id, local));
+        }
+
+        self.middleware.push((id, middleware));
+        id
+    }
+
+    /// Removes a middleware previously added to all tenants from their stores.
+    ///
+    /// Returns `false` if the middleware was already removed.
+    pub fn remove_middleware(&mut self, id: MiddlewareId) -> bool {
+        let len = self.middleware.len();
+        self.middleware.retain(|(other, _)| *other != id);
+
+        if self.middleware.len() == len {
+            return false;
+        }
+
+        for tenant in self.tenants.values_mut() {
+            if let Some(index) = tenant
+                .middleware
+                .iter()
+                .position(|(shared, _)| *shared == id)
+            {
+                let (_, local) = tenant.middleware.remove(index);
+                tenant.store.remove_middleware(local);
+            }
+        }
+
+        true
+    }
+
+    /// Removes a tenant, returning its store if it existed.
+    ///
+    /// Using the tenant again afterwards creates it anew from the init function.
+    pub fn evict(&mut self, key: &Key) -> Option<Store<State, Action>> {
+        self.tenants.remove(key).map(|tenant| tenant.store)
+    }
+
+    /// Removes all tenants for which the predicate returns `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::TenantStore;
+    /// #
+    /// # fn reducer(state: &u8, _: &bool) -> u8 {
+    /// #     state + 1
+    /// # }
+    /// #
+    /// # let mut store = TenantStore::new(reducer, |_: &u32| 0);
+    /// #
+    /// // Evict all tenants which never dispatched anything.
+    /// store.retain(|_, state| *state != 0);
+    /// ```
+    pub fn retain<F: FnMut(&Key, &State) -> bool>(&mut self, mut predicate: F) {
+        self.tenants
+            .retain(|key, tenant| predicate(key, tenant.store.state()));
+    }
+
+    /// Returns the number of tenants.
+    pub fn len(&self) -> usize {
+        self.tenants.len()
+    }
+
+    /// Returns `true` if there are no tenants.
+    pub fn is_empty(&self) -> bool {
+        self.tenants.is_empty()
+    }
+}
@@ -0,0 +1,28 @@
+use crate::Store;
+
+/// A transaction in progress on a store.
+///
+/// A transaction is passed to the function given to [`Store::transaction`](struct.Store.html#method.transaction).
+/// Actions dispatched through it are reduced right away, but the subscriptions are only called once the transaction succeeded.
+pub struct Transaction<'a, State, Action> {
+    store: &'a mut Store<State, Action>
+}
+
+impl<'a, State, Action> Transaction<'a, State, Action> {
+    /// Creates a new transaction on the store.
+    pub(crate) fn new(store: &'a mut Store<State, Action>) -> Self {
+        Self { store }
+    }
+
+    /// Returns the state of the store, with all actions dispatched through the transaction so far applied.
+    pub fn state(&self) -> &State {
+        self.store.state()
+    }
+
+    /// Dispatches an action as part of the transaction.
+    ///
+    /// See [`Store::dispatch`](struct.Store.html#method.dispatch).
+    pub fn dispatch(&mut self, action: Action) {
+        self.store.dispatch_now(action);
+    }
+}
@@ -0,0 +1,137 @@
+use redux_rs::Store;
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::atomic::{AtomicUsize, Ordering}
+};
+
+type State = i8;
+
+#[derive(Clone, Copy)]
+enum Action {
+    Increment,
+    Decrement
+}
+
+fn reducer(state: &State, action: &Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Decrement => state - 1
+    }
+}
+
+fn panicking_reducer(state: &State, action: &Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Decrement => panic!("Reducer panicked")
+    }
+}
+
+#[test]
+fn batch_commit() {
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    let mut store = Store::new(reducer, 0);
+    store.subscribe(|state: &State| {
+        assert_eq!(*state, 1);
+        CALLS.fetch_add(1, Ordering::SeqCst);
+    });
+    let mut batch = store.begin_batch();
+    batch.dispatch(Action::Increment);
+    batch.dispatch(Action::Increment);
+    batch.dispatch(Action::Decrement);
+    assert_eq!(*batch.state(), 0);
+    batch.commit();
+    assert_eq!(*store.state(), 1);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn batch_dropped() {
+    let mut store = Store::new(reducer, 0);
+    store.subscribe(|_: &State| {
+        panic!("Subscription called for a dropped batch");
+    });
+    let mut batch = store.begin_batch();
+    batch.dispatch(Action::Increment);
+    drop(batch);
+    assert_eq!(*store.state(), 0);
+}
+
+#[test]
+fn transaction_commit() {
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    let mut store = Store::new(reducer, 0);
+    store.subscribe(|_: &State| {
+        CALLS.fetch_add(1, Ordering::SeqCst);
+    });
+    let result: Result<i8, ()> = store.transaction(|tx| {
+        tx.dispatch(Action::Increment);
+        tx.dispatch(Action::Increment);
+        Ok(*tx.state())
+    });
+    assert_eq!(result, Ok(2));
+    assert_eq!(*store.state(), 2);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn transaction_rollback() {
+    let mut store = Store::new(reducer, 0);
+    store.subscribe(|_: &State| {
+        panic!("Subscription called for a failed transaction");
+    });
+    let result = store.transaction(|tx| {
+        tx.dispatch(Action::Increment);
+        tx.dispatch(Action::Increment);
+        if *tx.state() > 1 {
+            return Err("Too big");
+        }
+        Ok(())
+    });
+    assert_eq!(result, Err("Too big"));
+    assert_eq!(*store.state(), 0);
+}
+
+#[test]
+fn batch_panicked() {
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    let mut store = Store::new(panicking_reducer, 0);
+    store.subscribe(|_: &State| {
+        CALLS.fetch_add(1, Ordering::SeqCst);
+    });
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut batch = store.begin_batch();
+        batch.dispatch(Action::Increment);
+        batch.dispatch(Action::Decrement);
+        batch.commit();
+    }));
+    assert!(result.is_err());
+    assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+    store.dispatch(Action::Increment);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn transaction_panicked() {
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    let mut store = Store::new(panicking_reducer, 0);
+    store.subscribe(|_: &State| {
+        CALLS.fetch_add(1, Ordering::SeqCst);
+    });
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        store.transaction(|tx| {
+            tx.dispatch(Action::Increment);
+            tx.dispatch(Action::Decrement);
+            Ok::<(), ()>(())
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(*store.state(), 0);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+    store.dispatch(Action::Increment);
+    assert_eq!(*store.state(), 1);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+}
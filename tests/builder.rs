@@ -0,0 +1,65 @@
+use redux_rs::{Store, StoreBuilder};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+type State = i8;
+
+enum Action {
+    Increment,
+    Decrement
+}
+
+fn reducer(state: &State, action: &Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Decrement => state - 1
+    }
+}
+
+fn reducer_mut(state: &mut State, action: &Action) {
+    *state = reducer(state, action);
+}
+
+fn reverse_middleware(_: &mut Store<State, Action>, action: Action) -> Option<Action> {
+    match action {
+        Action::Increment => Some(Action::Decrement),
+        Action::Decrement => Some(Action::Increment)
+    }
+}
+
+#[test]
+fn builder_middleware() {
+    let mut store = Store::builder(reducer, 0)
+        .middleware(reverse_middleware)
+        .build();
+    store.dispatch(Action::Increment);
+    assert_eq!(*store.state(), -1);
+}
+
+#[test]
+fn builder_subscriptions() {
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    let mut store = StoreBuilder::new_mut(reducer_mut, 0)
+        .subscription(|_: &State| {
+            assert_eq!(CALLS.fetch_add(1, Ordering::SeqCst), 1);
+        })
+        .subscription_with_priority(1, |_: &State| {
+            assert_eq!(CALLS.fetch_add(1, Ordering::SeqCst), 0);
+        })
+        .group_subscription("ui", |_: &State| {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+        })
+        .build();
+    store.pause_group("ui");
+    store.dispatch(Action::Decrement);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn builder_mut() {
+    let mut store = Store::builder_mut(reducer_mut, 0)
+        .middleware(reverse_middleware)
+        .build();
+    store.dispatch(Action::Decrement);
+    assert_eq!(*store.state(), 1);
+}
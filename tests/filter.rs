@@ -0,0 +1,45 @@
+use redux_rs::{filter_middleware, filter_middleware_with_state, Store};
+
+type State = i8;
+
+enum Action {
+    Increment,
+    Decrement
+}
+
+fn reducer(state: &State, action: &Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Decrement => state - 1
+    }
+}
+
+fn is_increment(action: &Action) -> bool {
+    match action {
+        Action::Increment => true,
+        Action::Decrement => false
+    }
+}
+
+#[test]
+fn filter_action() {
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(filter_middleware!(State, Action, is_increment));
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Decrement);
+    assert_eq!(*store.state(), 1);
+}
+
+#[test]
+fn filter_with_state() {
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(filter_middleware_with_state!(
+        State,
+        Action,
+        |state: &State, _: &Action| *state < 2
+    ));
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+    assert_eq!(*store.state(), 2);
+}
@@ -0,0 +1,62 @@
+use redux_rs::Store;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+type State = i8;
+
+#[derive(Clone, Copy)]
+enum Action {
+    Increment,
+    Decrement
+}
+
+fn reducer(state: &State, action: &Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Decrement => state - 1
+    }
+}
+
+#[test]
+fn group_paused() {
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    let mut store = Store::new(reducer, 0);
+    store.subscribe_to_group("ui", |_: &State| {
+        CALLS.fetch_add(1, Ordering::SeqCst);
+    });
+    store.pause_group("ui");
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Decrement);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn group_resumed() {
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    let mut store = Store::new(reducer, 0);
+    store.subscribe_to_group("ui", |_: &State| {
+        CALLS.fetch_add(1, Ordering::SeqCst);
+    });
+    store.pause_group("ui");
+    store.dispatch(Action::Increment);
+    store.resume_group("ui");
+    store.dispatch(Action::Increment);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn group_other_groups_unaffected() {
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    let mut store = Store::new(reducer, 0);
+    store.subscribe(|_: &State| {
+        CALLS.fetch_add(1, Ordering::SeqCst);
+    });
+    store.subscribe_to_group("persistence", |_: &State| {
+        CALLS.fetch_add(1, Ordering::SeqCst);
+    });
+    store.pause_group("ui");
+    store.dispatch(Action::Increment);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,43 @@
+use redux_rs::Store;
+
+type State = Vec<i8>;
+
+#[derive(Clone, Copy)]
+enum Action {
+    Push(i8),
+    Pop
+}
+
+fn reducer_mut(state: &mut State, action: &Action) {
+    match action {
+        Action::Push(value) => state.push(*value),
+        Action::Pop => {
+            state.pop();
+        }
+    }
+}
+
+fn reducer(state: &State, action: &Action) -> State {
+    let mut state = state.clone();
+    reducer_mut(&mut state, action);
+    state.push(0);
+    state
+}
+
+#[test]
+fn in_place_dispatch() {
+    let mut store = Store::new_mut(reducer_mut, Vec::new());
+    store.dispatch(Action::Push(1));
+    store.dispatch(Action::Push(2));
+    store.dispatch(Action::Pop);
+    assert_eq!(*store.state(), vec![1]);
+}
+
+#[test]
+fn in_place_replace() {
+    let mut store = Store::new(reducer, Vec::new());
+    store.dispatch(Action::Push(1));
+    store.replace_reducer_mut(reducer_mut);
+    store.dispatch(Action::Push(2));
+    assert_eq!(*store.state(), vec![1, 0, 2]);
+}
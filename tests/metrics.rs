@@ -0,0 +1,64 @@
+use redux_rs::Store;
+#[cfg(feature = "std")]
+use std::{thread, time::Duration};
+
+type State = i8;
+
+enum Action {
+    Increment,
+    Decrement
+}
+
+fn reducer(state: &State, action: &Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Decrement => state - 1
+    }
+}
+
+fn only_increment_middleware(_: &mut Store<State, Action>, action: Action) -> Option<Action> {
+    match action {
+        Action::Increment => Some(action),
+        Action::Decrement => None
+    }
+}
+
+#[test]
+fn metrics_count_dispatched() {
+    let mut store = Store::new(reducer, 0);
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Decrement);
+    assert_eq!(store.metrics().actions_dispatched, 2);
+    assert_eq!(store.metrics().actions_reduced, 2);
+}
+
+#[test]
+fn metrics_count_halted() {
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(only_increment_middleware);
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Decrement);
+    assert_eq!(store.metrics().actions_dispatched, 2);
+    assert_eq!(store.metrics().actions_reduced, 1);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn metrics_timings_disabled() {
+    let mut store = Store::new(reducer, 0);
+    store.subscribe(|_: &State| {});
+    store.dispatch(Action::Increment);
+    assert_eq!(store.metrics().reduce_time, Duration::ZERO);
+    assert_eq!(store.metrics().subscriber_time, Duration::ZERO);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn metrics_timings_enabled() {
+    let mut store = Store::builder(reducer, 0)
+        .subscription(|_: &State| thread::sleep(Duration::from_millis(1)))
+        .timings()
+        .build();
+    store.dispatch(Action::Increment);
+    assert!(store.metrics().subscriber_time >= Duration::from_millis(1));
+}
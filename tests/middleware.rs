@@ -1,4 +1,5 @@
-use redux_rs::Store;
+use redux_rs::{MiddlewareId, Store};
+use std::sync::Mutex;
 
 type State = i8;
 
@@ -59,3 +60,59 @@ fn only_increment_middleware_decrement() {
     store.dispatch(Action::Decrement);
     assert_eq!(*store.state(), 0);
 }
+
+#[test]
+fn remove_middleware() {
+    let mut store = Store::new(reducer, 0);
+    let reverse = store.add_middleware(reverse_middleware);
+    store.add_middleware(only_increment_middleware);
+    store.dispatch(Action::Decrement);
+    assert_eq!(*store.state(), 1);
+    assert!(store.remove_middleware(reverse));
+    assert!(!store.remove_middleware(reverse));
+    store.dispatch(Action::Decrement);
+    store.dispatch(Action::Increment);
+    assert_eq!(*store.state(), 2);
+}
+
+static REMOVED: Mutex<Option<MiddlewareId>> = Mutex::new(None);
+
+fn self_removing_middleware(store: &mut Store<State, Action>, action: Action) -> Option<Action> {
+    if let Some(id) = REMOVED.lock().unwrap().take() {
+        store.remove_middleware(id);
+    }
+
+    Some(action)
+}
+
+fn adding_middleware(store: &mut Store<State, Action>, action: Action) -> Option<Action> {
+    store.add_middleware(reverse_middleware);
+    Some(action)
+}
+
+#[test]
+fn remove_middleware_while_dispatching() {
+    let mut store = Store::new(reducer, 0);
+    let id = store.add_middleware(self_removing_middleware);
+    store.add_middleware(reverse_middleware);
+    *REMOVED.lock().unwrap() = Some(id);
+
+    store.dispatch(Action::Increment);
+    assert_eq!(*store.state(), -1);
+
+    store.dispatch(Action::Increment);
+    assert_eq!(*store.state(), -2);
+}
+
+#[test]
+fn add_middleware_while_dispatching() {
+    let mut store = Store::new(reducer, 0);
+    let adding = store.add_middleware(adding_middleware);
+
+    store.dispatch(Action::Increment);
+    assert_eq!(*store.state(), 1);
+
+    store.remove_middleware(adding);
+    store.dispatch(Action::Increment);
+    assert_eq!(*store.state(), 0);
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "std")]
+
+use redux_rs::{Store, SubscriptionId};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Mutex
+};
+
+type State = i8;
+
+#[derive(Clone, Copy)]
+enum Action {
+    Increment,
+    Decrement
+}
+
+fn reducer(state: &State, action: &Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Decrement => state - 1
+    }
+}
+
+#[test]
+fn panic_isolated() {
+    static PANICS: AtomicUsize = AtomicUsize::new(0);
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+    static PANICKING: Mutex<Option<SubscriptionId>> = Mutex::new(None);
+
+    let mut store = Store::new(reducer, 0);
+    store.set_panic_handler(|id, payload| {
+        assert_eq!(Some(id), *PANICKING.lock().unwrap());
+        assert_eq!(
+            payload.downcast_ref::<&str>(),
+            Some(&"Subscription panicked")
+        );
+        PANICS.fetch_add(1, Ordering::SeqCst);
+    });
+    let panicking = store.subscribe(|_: &State| panic!("Subscription panicked"));
+    *PANICKING.lock().unwrap() = Some(panicking);
+    store.subscribe(|_: &State| {
+        CALLS.fetch_add(1, Ordering::SeqCst);
+    });
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Decrement);
+    assert_eq!(PANICS.load(Ordering::SeqCst), 1);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+#[should_panic(expected = "Subscription panicked")]
+fn panic_without_handler() {
+    let mut store = Store::new(reducer, 0);
+    store.subscribe(|_: &State| panic!("Subscription panicked"));
+    store.dispatch(Action::Increment);
+}
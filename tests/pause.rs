@@ -0,0 +1,125 @@
+use redux_rs::Store;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+type State = i8;
+
+#[derive(Clone, Copy)]
+enum Action {
+    Increment,
+    Double
+}
+
+fn reducer(state: &State, action: &Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Double => state * 2
+    }
+}
+
+#[test]
+fn pause_buffers() {
+    let mut store = Store::new(reducer, 0);
+    store.pause();
+    assert!(store.is_paused());
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+    assert_eq!(*store.state(), 0);
+}
+
+#[test]
+fn resume_in_order() {
+    let mut store = Store::new(reducer, 0);
+    store.pause();
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Double);
+    store.resume();
+    assert!(!store.is_paused());
+    assert_eq!(*store.state(), 2);
+    store.dispatch(Action::Increment);
+    assert_eq!(*store.state(), 3);
+}
+
+#[test]
+fn pending_actions() {
+    let mut store = Store::new(reducer, 0);
+    store.pause();
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+    assert_eq!(store.pending_actions(), 2);
+    store.resume();
+    assert_eq!(store.pending_actions(), 0);
+    assert_eq!(store.metrics().max_pending_actions, 2);
+}
+
+#[test]
+fn resume_batched() {
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    let mut store = Store::new(reducer, 0);
+    store.subscribe(|state: &State| {
+        assert_eq!(*state, 4);
+        CALLS.fetch_add(1, Ordering::SeqCst);
+    });
+    store.pause();
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Double);
+    store.resume_batched();
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn batch_while_paused() {
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    let mut store = Store::new(reducer, 0);
+    store.subscribe(|_: &State| {
+        CALLS.fetch_add(1, Ordering::SeqCst);
+    });
+    store.pause();
+    let mut batch = store.begin_batch();
+    batch.dispatch(Action::Increment);
+    batch.dispatch(Action::Double);
+    batch.commit();
+    assert_eq!(*store.state(), 0);
+    assert_eq!(store.pending_actions(), 2);
+    store.resume();
+    assert_eq!(*store.state(), 2);
+    assert_eq!(store.pending_actions(), 0);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn batch_while_paused_in_order() {
+    let mut store = Store::new(reducer, 0);
+    store.pause();
+    store.dispatch(Action::Increment);
+    let mut batch = store.begin_batch();
+    batch.dispatch(Action::Double);
+    batch.commit();
+    store.resume();
+    assert_eq!(*store.state(), 2);
+}
+
+#[test]
+fn transaction_while_paused() {
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    let mut store = Store::new(reducer, 0);
+    store.subscribe(|_: &State| {
+        CALLS.fetch_add(1, Ordering::SeqCst);
+    });
+    store.pause();
+    store.dispatch(Action::Increment);
+    let result: Result<(), ()> = store.transaction(|tx| {
+        tx.dispatch(Action::Double);
+        Ok(())
+    });
+    assert_eq!(result, Ok(()));
+    assert_eq!(*store.state(), 2);
+    assert_eq!(store.pending_actions(), 0);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+    assert!(store.is_paused());
+    store.dispatch(Action::Increment);
+    assert_eq!(*store.state(), 2);
+}
@@ -0,0 +1,47 @@
+use redux_rs::Store;
+
+type State = i8;
+
+enum Action {
+    Add(i8)
+}
+
+fn reducer(state: &State, action: &Action) -> State {
+    match action {
+        Action::Add(value) => state + value
+    }
+}
+
+fn clamp_preprocessor(action: Action) -> Action {
+    match action {
+        Action::Add(value) => Action::Add(value.clamp(-1, 1))
+    }
+}
+
+fn only_positive_middleware(_: &mut Store<State, Action>, action: Action) -> Option<Action> {
+    match action {
+        Action::Add(value) if value > 1 => panic!("Middleware saw an unprocessed action"),
+        Action::Add(value) if value < 0 => None,
+        action => Some(action)
+    }
+}
+
+#[test]
+fn preprocessor_before_middleware() {
+    let mut store = Store::new(reducer, 0);
+    store.set_preprocessor(clamp_preprocessor);
+    store.add_middleware(only_positive_middleware);
+    store.dispatch(Action::Add(5));
+    store.dispatch(Action::Add(-5));
+    assert_eq!(*store.state(), 1);
+}
+
+#[test]
+fn preprocessor_cleared() {
+    let mut store = Store::new(reducer, 0);
+    store.set_preprocessor(clamp_preprocessor);
+    store.dispatch(Action::Add(5));
+    store.clear_preprocessor();
+    store.dispatch(Action::Add(5));
+    assert_eq!(*store.state(), 6);
+}
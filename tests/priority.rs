@@ -0,0 +1,50 @@
+use redux_rs::Store;
+use std::sync::atomic::{AtomicI8, Ordering};
+
+type State = i8;
+
+#[derive(Clone, Copy)]
+enum Action {
+    Increment,
+    Decrement
+}
+
+fn reducer(state: &State, action: &Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Decrement => state - 1
+    }
+}
+
+#[test]
+fn priority_higher_first() {
+    static LAST: AtomicI8 = AtomicI8::new(0);
+
+    let mut store = Store::new(reducer, 0);
+    store.subscribe(|_: &State| {
+        assert_eq!(LAST.swap(0, Ordering::SeqCst), 1);
+    });
+    store.subscribe_with_priority(1, |_: &State| {
+        assert_eq!(LAST.swap(1, Ordering::SeqCst), 2);
+    });
+    store.subscribe_with_priority(2, |_: &State| {
+        assert_eq!(LAST.swap(2, Ordering::SeqCst), 0);
+    });
+    store.dispatch(Action::Increment);
+    assert_eq!(LAST.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn priority_same_in_order() {
+    static LAST: AtomicI8 = AtomicI8::new(0);
+
+    let mut store = Store::new(reducer, 0);
+    store.subscribe_with_priority(-1, |_: &State| {
+        assert_eq!(LAST.swap(1, Ordering::SeqCst), 0);
+    });
+    store.subscribe_with_priority(-1, |_: &State| {
+        assert_eq!(LAST.swap(2, Ordering::SeqCst), 1);
+    });
+    store.dispatch(Action::Decrement);
+    assert_eq!(LAST.load(Ordering::SeqCst), 2);
+}
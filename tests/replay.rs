@@ -0,0 +1,31 @@
+use redux_rs::Store;
+
+type State = i8;
+
+#[derive(Clone, Copy)]
+enum Action {
+    Increment,
+    Decrement
+}
+
+fn reducer(state: &State, action: &Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Decrement => state - 1
+    }
+}
+
+#[test]
+fn replay_matches_dispatch() {
+    let actions = vec![Action::Increment, Action::Increment, Action::Decrement];
+    let mut store = Store::new(reducer, 0);
+    for action in actions.iter() {
+        store.dispatch(*action);
+    }
+    assert_eq!(Store::replay(reducer, 0, &actions), *store.state());
+}
+
+#[test]
+fn replay_empty() {
+    assert_eq!(Store::replay(reducer, 3, Vec::<Action>::new()), 3);
+}
@@ -34,3 +34,32 @@ fn subscription_decrement() {
     store.subscribe(listener);
     store.dispatch(Action::Decrement);
 }
+
+#[test]
+fn unsubscribe() {
+    let mut store = Store::new(reducer, 0);
+    let listener = store.subscribe(|_: &State| {
+        panic!("Removed subscription called");
+    });
+    assert!(store.unsubscribe(listener));
+    assert!(!store.unsubscribe(listener));
+    store.dispatch(Action::Increment);
+}
+
+#[test]
+fn remove_subscriptions() {
+    let mut store = Store::new(reducer, 0);
+    let first = store.subscribe(|_: &State| {
+        panic!("Removed subscription called");
+    });
+    let second = store.subscribe_with_priority(1, |_: &State| {
+        panic!("Removed subscription called");
+    });
+    let kept = store.subscribe_to_group("ui", |state: &State| {
+        assert_eq!(*state, 1);
+    });
+    store.remove_subscriptions_where(|id| id == first || id == second);
+    store.dispatch(Action::Increment);
+    store.clear_subscriptions();
+    assert!(!store.unsubscribe(kept));
+}
@@ -0,0 +1,73 @@
+use redux_rs::{Store, TenantStore};
+
+type State = i8;
+
+enum Action {
+    Increment,
+    Decrement
+}
+
+fn reducer(state: &State, action: &Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Decrement => state - 1
+    }
+}
+
+fn init(key: &u8) -> State {
+    *key as State
+}
+
+fn only_increment_middleware(_: &mut Store<State, Action>, action: Action) -> Option<Action> {
+    match action {
+        Action::Increment => Some(action),
+        Action::Decrement => None
+    }
+}
+
+#[test]
+fn tenant_isolated() {
+    let mut store = TenantStore::new(reducer, init);
+    store.dispatch(1, Action::Increment);
+    store.dispatch(2, Action::Decrement);
+    assert_eq!(store.state(&1), Some(&2));
+    assert_eq!(store.state(&2), Some(&1));
+    assert_eq!(store.state(&3), None);
+}
+
+#[test]
+fn tenant_evict() {
+    let mut store = TenantStore::new(reducer, init);
+    store.dispatch(1, Action::Increment);
+    store.dispatch(2, Action::Increment);
+    assert_eq!(store.evict(&1).map(|store| *store.state()), Some(2));
+    store.retain(|_, state| *state != 3);
+    assert!(store.is_empty());
+}
+
+#[test]
+fn tenant_shared_middleware() {
+    let mut store = TenantStore::new(reducer, init);
+    store.dispatch(1, Action::Increment);
+    store.add_middleware(only_increment_middleware);
+    store.dispatch(1, Action::Decrement);
+    store.dispatch(2, Action::Decrement);
+    assert_eq!(store.state(&1), Some(&2));
+    assert_eq!(store.state(&2), Some(&2));
+}
+
+#[test]
+fn tenant_remove_middleware() {
+    let mut store = TenantStore::new(reducer, init);
+    store.dispatch(1, Action::Increment);
+    let only_increment = store.add_middleware(only_increment_middleware);
+    store.dispatch(2, Action::Decrement);
+    assert!(store.remove_middleware(only_increment));
+    assert!(!store.remove_middleware(only_increment));
+    store.dispatch(1, Action::Decrement);
+    store.dispatch(2, Action::Decrement);
+    store.dispatch(3, Action::Decrement);
+    assert_eq!(store.state(&1), Some(&1));
+    assert_eq!(store.state(&2), Some(&1));
+    assert_eq!(store.state(&3), Some(&2));
+}